@@ -38,29 +38,300 @@
 
 #![warn(clippy::all, missing_docs, nonstandard_style, future_incompatible)]
 
-/// `&[-1, 1]` <--> `-1_1`
-pub mod vec_num {
-    use serde::{self, de::Error, Deserialize, Deserializer, Serializer};
-    use std::{fmt::Display, str::FromStr};
+use std::str::FromStr;
 
-    /// Serializer
-    pub fn serialize<S, T>(list: &[T], serializer: S) -> Result<S::Ok, S::Error>
+/// Escaping shared by the delimiter-joining modules.
+///
+/// Joining elements with a plain separator corrupts data when an element's string form
+/// contains that separator. Before joining, every `\` becomes `\\` and every occurrence of
+/// the separator becomes `\` followed by the separator; splitting then scans
+/// character-by-character honoring `\` so escaped separators aren't treated as delimiters.
+/// Applying this independently at each nesting level (as [`vec_vec_num`] does) composes
+/// correctly: unescaping the outermost level first reveals the inner level's own escaping
+/// untouched.
+///
+/// `sep` must not be `'\0'`: that character is reserved to mark an empty element (see
+/// [`EMPTY_ELEMENT`]).
+mod escaping {
+    /// Reserved marker for "this element is the empty string", as opposed to "there are
+    /// zero elements", which also joins to the empty string - without this, a one-element
+    /// list of `""` would be indistinguishable from an empty list once joined. [`escape`]
+    /// never otherwise emits a `\` followed by this character (it only ever escapes `\`
+    /// itself or `sep`), so [`split`] can treat that exact pair as the marker rather than a
+    /// literal character.
+    const EMPTY_ELEMENT: char = '\0';
+
+    /// Escape `\` and `sep` in `s` so it round-trips through [`split`]; an empty `s` becomes
+    /// the reserved [`EMPTY_ELEMENT`] marker instead of staying empty.
+    pub(crate) fn escape(s: &str, sep: char) -> String {
+        if s.is_empty() {
+            return format!("\\{EMPTY_ELEMENT}");
+        }
+        let mut out = String::with_capacity(s.len());
+        for c in s.chars() {
+            if c == '\\' || c == sep {
+                out.push('\\');
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    /// Split `s` on unescaped occurrences of `sep`, reversing [`escape`].
+    ///
+    /// A trailing unpaired `\` is kept as-is rather than dropped. An empty `s` is zero
+    /// elements rather than one empty element - the latter is always represented by
+    /// [`EMPTY_ELEMENT`] instead, per [`escape`] - which matters when `s` itself is one
+    /// segment nested inside an outer [`split`] call.
+    pub(crate) fn split(s: &str, sep: char) -> Vec<String> {
+        if s.is_empty() {
+            return Vec::new();
+        }
+        let mut parts = Vec::new();
+        let mut current = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some(EMPTY_ELEMENT) => {}
+                    Some(escaped) => current.push(escaped),
+                    None => current.push('\\'),
+                }
+            } else if c == sep {
+                parts.push(std::mem::take(&mut current));
+            } else {
+                current.push(c);
+            }
+        }
+        parts.push(current);
+        parts
+    }
+}
+
+/// Type-level separator, à la serde_with's `StringWithSeparator`.
+///
+/// Implement this for a zero-sized marker type to plug a new delimiter into [`sep_vec`]
+/// without writing a whole new module.
+pub trait Separator {
+    /// The separator character used to join and split elements.
+    ///
+    /// A single `char` rather than a `&'static str`: escaping (see [`escaping`]) only ever
+    /// escapes one character, so a multi-character separator would silently corrupt data
+    /// instead of failing loudly.
+    ///
+    /// Must not be `'\0'`: `escaping` reserves that character as its empty-element marker,
+    /// and [`sep_vec`] rejects it with a descriptive error rather than letting it collide.
+    fn separator() -> char;
+}
+
+/// `_`
+pub struct UnderscoreSeparator;
+
+impl Separator for UnderscoreSeparator {
+    fn separator() -> char {
+        '_'
+    }
+}
+
+/// ` `
+pub struct SpaceSeparator;
+
+impl Separator for SpaceSeparator {
+    fn separator() -> char {
+        ' '
+    }
+}
+
+/// `,`
+pub struct CommaSeparator;
+
+impl Separator for CommaSeparator {
+    fn separator() -> char {
+        ','
+    }
+}
+
+/// `&[-1, 1]` <--> `-1_1`, with the delimiter picked by a [`Separator`] type param
+///
+/// `#[serde(with = "...")]` can't pass generic params, so plug this in via
+/// `serialize_with`/`deserialize_with` and a turbofish instead:
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serde_csv_extra::{sep_vec, CommaSeparator};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo {
+///     #[serde(
+///         serialize_with = "sep_vec::serialize::<_, CommaSeparator, _>",
+///         deserialize_with = "sep_vec::deserialize::<_, CommaSeparator, _>"
+///     )]
+///     list: Vec<i32>,
+/// }
+/// ```
+///
+/// [`vec_num`] is a thin wrapper over this with [`UnderscoreSeparator`], kept around for
+/// backward compatibility.
+pub mod sep_vec {
+    use super::{escaping, Separator};
+    use serde::{
+        self,
+        de::{Error, SeqAccess, Visitor},
+        ser::Error as SerError,
+        Deserialize, Deserializer, Serializer,
+    };
+    use std::{fmt, fmt::Display, marker::PhantomData, str::FromStr};
+
+    /// `escaping` reserves `'\0'` as the empty-element marker (see [`escaping::escape`]), so
+    /// a separator of `'\0'` would make a literal NUL inside an element's string form
+    /// indistinguishable from that marker. Reject it instead of silently losing data.
+    fn check_sep<Sep: Separator>() -> Result<char, String> {
+        let sep = Sep::separator();
+        if sep == '\0' {
+            return Err(
+                "Separator::separator must not be '\\0': escaping reserves it as the \
+                 empty-element marker"
+                    .to_string(),
+            );
+        }
+        Ok(sep)
+    }
+
+    /// Serializer, escaping `\` and the separator in each element so they round-trip
+    pub fn serialize<S, Sep, T>(list: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Sep: Separator,
+        T: ToString,
+    {
+        let sep = check_sep::<Sep>().map_err(SerError::custom)?;
+        let mut buf = [0u8; 4];
+        let s = list
+            .iter()
+            .map(|v| escaping::escape(&v.to_string(), sep))
+            .collect::<Vec<_>>()
+            .join(sep.encode_utf8(&mut buf) as &str);
+        serializer.serialize_str(&s)
+    }
+
+    struct SepVecVisitor<Sep, T>(PhantomData<(Sep, T)>);
+
+    impl<'de, Sep, T> Visitor<'de> for SepVecVisitor<Sep, T>
+    where
+        Sep: Separator,
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a delimited string")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            if v.is_empty() {
+                return Ok(Vec::new());
+            }
+            escaping::split(v, Sep::separator())
+                .into_iter()
+                .map(|part| part.parse().map_err(Error::custom))
+                .collect()
+        }
+
+        fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+            self.visit_str(&v)
+        }
+    }
+
+    /// Deserializer, reversing the escaping done by [`serialize`] for a delimited string
+    pub fn deserialize<'de, D, Sep, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Sep: Separator,
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        check_sep::<Sep>().map_err(Error::custom)?;
+        deserializer.deserialize_str(SepVecVisitor::<Sep, T>(PhantomData))
+    }
+
+    /// Accepts either a delimited string (CSV) or a native sequence (JSON, CBOR, ...),
+    /// à la serde_with's `PickFirst`, so one annotation works transparently across
+    /// backends. Requires `T: Display + Deserialize` in addition to [`deserialize`]'s
+    /// bounds, so it's a separate opt-in rather than a replacement - swapping it in can break
+    /// callers whose element type implements `FromStr` but not `Display` or `Deserialize`.
+    struct SepVecPickFirstVisitor<Sep, T>(PhantomData<(Sep, T)>);
+
+    impl<'de, Sep, T> Visitor<'de> for SepVecPickFirstVisitor<Sep, T>
+    where
+        Sep: Separator,
+        T: FromStr + Display + Deserialize<'de>,
+        <T as FromStr>::Err: Display,
+    {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a delimited string or a sequence")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            if v.is_empty() {
+                return Ok(Vec::new());
+            }
+            escaping::split(v, Sep::separator())
+                .into_iter()
+                .map(|part| part.parse().map_err(Error::custom))
+                .collect()
+        }
+
+        fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+            self.visit_str(&v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(item) = seq.next_element()? {
+                out.push(item);
+            }
+            Ok(out)
+        }
+    }
+
+    /// Deserializer matching [`deserialize`], but also accepting a native sequence like
+    /// `[-1, 1]` - see [`SepVecPickFirstVisitor`].
+    pub fn deserialize_pick_first<'de, D, Sep, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Sep: Separator,
+        T: FromStr + Display + Deserialize<'de>,
+        <T as FromStr>::Err: Display,
+    {
+        check_sep::<Sep>().map_err(Error::custom)?;
+        deserializer.deserialize_any(SepVecPickFirstVisitor::<Sep, T>(PhantomData))
+    }
+
+    /// Serializer skipping escaping. Faster, but corrupts data if an element's string form
+    /// contains the separator - only use it when that's known not to happen.
+    pub fn serialize_raw<S, Sep, T>(list: &[T], serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
+        Sep: Separator,
         T: ToString,
     {
+        let mut buf = [0u8; 4];
         let s = list
             .iter()
             .map(ToString::to_string)
             .collect::<Vec<_>>()
-            .join("_");
+            .join(Sep::separator().encode_utf8(&mut buf) as &str);
         serializer.serialize_str(&s)
     }
 
-    /// Deserializer
-    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    /// Deserializer matching [`serialize_raw`]
+    pub fn deserialize_raw<'de, D, Sep, T>(deserializer: D) -> Result<Vec<T>, D::Error>
     where
         D: Deserializer<'de>,
+        Sep: Separator,
         T: FromStr + Display,
         <T as FromStr>::Err: Display,
     {
@@ -68,19 +339,191 @@ pub mod vec_num {
         if s.is_empty() {
             return Ok(Vec::new());
         }
-        s.split('_')
+        s.split(Sep::separator())
             .map(|n| n.parse().map_err(Error::custom))
             .collect()
     }
 }
 
-/// `&[[vec![-1, 1], vec![1, -1]]` <--> `-1_1|1_-1`
-pub mod vec_vec_num {
-    use serde::{self, Deserialize, Deserializer, Serializer};
+/// `&[-1, 1]` <--> `-1_1`
+///
+/// A thin wrapper over [`sep_vec`] fixed to [`UnderscoreSeparator`], kept for backward
+/// compatibility. Use [`sep_vec`] directly for other delimiters.
+pub mod vec_num {
+    use super::{sep_vec, UnderscoreSeparator};
+    use serde::{Deserialize, Deserializer, Serializer};
     use std::{fmt::Display, str::FromStr};
 
     /// Serializer
+    pub fn serialize<S, T>(list: &[T], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: ToString,
+    {
+        sep_vec::serialize::<S, UnderscoreSeparator, T>(list, serializer)
+    }
+
+    /// Deserializer
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        sep_vec::deserialize::<D, UnderscoreSeparator, T>(deserializer)
+    }
+
+    /// Deserializer matching [`deserialize`], but also accepting a native sequence like
+    /// `[-1, 1]`, so the same annotation works whether the source is CSV or JSON/CBOR.
+    /// Requires `T: Display + Deserialize` in addition to [`deserialize`]'s bounds - a
+    /// separate opt-in so it doesn't narrow what [`deserialize`] itself accepts.
+    pub fn deserialize_pick_first<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr + Display + Deserialize<'de>,
+        <T as FromStr>::Err: Display,
+    {
+        sep_vec::deserialize_pick_first::<D, UnderscoreSeparator, T>(deserializer)
+    }
+}
+
+/// `&[[vec![-1, 1], vec![1, -1]]` <--> `-1_1|1_-1`
+pub mod vec_vec_num {
+    use crate::escaping;
+    use serde::{
+        self,
+        de::{Error, SeqAccess, Visitor},
+        Deserialize, Deserializer, Serializer,
+    };
+    use std::{fmt, fmt::Display, marker::PhantomData, str::FromStr};
+
+    const COL_SEP: char = '_';
+    const ROW_SEP: char = '|';
+
+    /// Serializer, escaping `\` and the separators at both nesting levels
     pub fn serialize<S, T>(rows: &[Vec<T>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: ToString,
+    {
+        let s = rows
+            .iter()
+            .map(|row| {
+                let row = row
+                    .iter()
+                    .map(|v| escaping::escape(&v.to_string(), COL_SEP))
+                    .collect::<Vec<_>>()
+                    .join("_");
+                escaping::escape(&row, ROW_SEP)
+            })
+            .collect::<Vec<_>>()
+            .join("|");
+        serializer.serialize_str(&s)
+    }
+
+    struct VecVecNumVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for VecVecNumVisitor<T>
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        type Value = Vec<Vec<T>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a delimited string")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            if v.is_empty() {
+                return Ok(Vec::new());
+            }
+            escaping::split(v, ROW_SEP)
+                .into_iter()
+                .map(|line| {
+                    escaping::split(&line, COL_SEP)
+                        .into_iter()
+                        .map(|col| col.parse().map_err(Error::custom))
+                        .collect()
+                })
+                .collect()
+        }
+
+        fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+            self.visit_str(&v)
+        }
+    }
+
+    /// Deserializer, reversing the escaping done by [`serialize`] for a delimited string
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<Vec<T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr,
+        <T as FromStr>::Err: Display,
+    {
+        deserializer.deserialize_str(VecVecNumVisitor::<T>(PhantomData))
+    }
+
+    /// Accepts either a delimited string (CSV) or a native sequence of sequences (JSON,
+    /// CBOR, ...), à la serde_with's `PickFirst`. Requires `T: Display + Deserialize` in
+    /// addition to [`deserialize`]'s bounds, so it's a separate opt-in rather than a
+    /// replacement - swapping it in can break callers whose element type implements
+    /// `FromStr` but not `Display` or `Deserialize`.
+    struct VecVecNumPickFirstVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> Visitor<'de> for VecVecNumPickFirstVisitor<T>
+    where
+        T: FromStr + Display + Deserialize<'de>,
+        <T as FromStr>::Err: Display,
+    {
+        type Value = Vec<Vec<T>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a delimited string or a sequence of sequences")
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            if v.is_empty() {
+                return Ok(Vec::new());
+            }
+            escaping::split(v, ROW_SEP)
+                .into_iter()
+                .map(|line| {
+                    escaping::split(&line, COL_SEP)
+                        .into_iter()
+                        .map(|col| col.parse().map_err(Error::custom))
+                        .collect()
+                })
+                .collect()
+        }
+
+        fn visit_string<E: Error>(self, v: String) -> Result<Self::Value, E> {
+            self.visit_str(&v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(row) = seq.next_element()? {
+                out.push(row);
+            }
+            Ok(out)
+        }
+    }
+
+    /// Deserializer matching [`deserialize`], but also accepting a native sequence of
+    /// sequences like `[[0], [-1, 1]]` - see [`VecVecNumPickFirstVisitor`].
+    pub fn deserialize_pick_first<'de, D, T>(deserializer: D) -> Result<Vec<Vec<T>>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: FromStr + Display + Deserialize<'de>,
+        <T as FromStr>::Err: Display,
+    {
+        deserializer.deserialize_any(VecVecNumPickFirstVisitor::<T>(PhantomData))
+    }
+
+    /// Serializer skipping escaping. Faster, but corrupts data if an element's string form
+    /// contains either separator - only use it when that's known not to happen.
+    pub fn serialize_raw<S, T>(rows: &[Vec<T>], serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
         T: ToString,
@@ -98,8 +541,8 @@ pub mod vec_vec_num {
         serializer.serialize_str(&s)
     }
 
-    /// Deserializer
-    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Vec<Vec<T>>, D::Error>
+    /// Deserializer matching [`serialize_raw`]
+    pub fn deserialize_raw<'de, D, T>(deserializer: D) -> Result<Vec<Vec<T>>, D::Error>
     where
         D: Deserializer<'de>,
         T: FromStr + Display,
@@ -111,9 +554,9 @@ pub mod vec_vec_num {
         }
 
         let mut rows = Vec::new();
-        for line in s.split('|') {
+        for line in s.split(ROW_SEP) {
             let mut row = Vec::new();
-            for col_str in line.split('_') {
+            for col_str in line.split(COL_SEP) {
                 row.push(col_str.parse().map_err(serde::de::Error::custom)?)
             }
             rows.push(row);
@@ -123,6 +566,182 @@ pub mod vec_vec_num {
     }
 }
 
+/// A stack of delimiters for [`nd_num`], one per nesting level, outermost last.
+///
+/// Mirrors [`Separator`] but for arbitrarily-nested vectors: index `0` is the innermost
+/// delimiter (joining scalars), index `1` the next level out, and so on.
+///
+/// A stack of `char`s rather than `&'static str`s: escaping (see [`escaping`]) only ever
+/// escapes one character, so a multi-character delimiter would silently corrupt data
+/// instead of failing loudly.
+pub trait Separators {
+    /// The delimiter stack, outermost-last.
+    ///
+    /// None of the entries may be `'\0'`: `escaping` reserves that character as its
+    /// empty-element marker, and [`nd_num`] rejects a stack containing it with a
+    /// descriptive error rather than letting it collide.
+    fn separators() -> &'static [char];
+}
+
+/// `['_', '|', ';', ':']`, supporting nesting up to 4 levels deep
+pub struct DefaultSeparators;
+
+impl Separators for DefaultSeparators {
+    fn separators() -> &'static [char] {
+        &['_', '|', ';', ':']
+    }
+}
+
+/// Recursive conversion between an arbitrarily-nested `Vec<Vec<...<T>>>` and a delimited
+/// string, used by [`nd_num`].
+///
+/// A scalar leaf type (depth 0) is the base case; `Vec<U: NdNum<Seps>>` is the recursive
+/// case, picking `Seps::separators()[Self::DEPTH - 1]` as its own delimiter and recursing
+/// one level in for each element.
+///
+/// The base case can't be a blanket `impl<T: FromStr + Display> NdNum<Seps> for T`: since
+/// `FromStr`/`Display` are foreign traits, the compiler can't rule out some future upstream
+/// impl of them for `Vec<_>`, which would conflict with our own `Vec<U>` impl below. So
+/// leaf types are enumerated individually via [`impl_nd_num_leaf`] instead.
+pub trait NdNum<Seps: Separators>: Sized {
+    /// Nesting depth: `0` for a scalar, `1` for `Vec<scalar>`, `2` for `Vec<Vec<scalar>>`, ...
+    const DEPTH: usize;
+
+    /// Render `self` as a delimited string.
+    fn to_nd_string(&self) -> String;
+
+    /// Parse a delimited string produced by [`to_nd_string`](NdNum::to_nd_string).
+    fn from_nd_str(s: &str) -> Result<Self, String>;
+}
+
+macro_rules! impl_nd_num_leaf {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl<Seps: Separators> NdNum<Seps> for $t {
+                const DEPTH: usize = 0;
+
+                fn to_nd_string(&self) -> String {
+                    self.to_string()
+                }
+
+                fn from_nd_str(s: &str) -> Result<Self, String> {
+                    s.parse().map_err(|e: <$t as FromStr>::Err| e.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_nd_num_leaf!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, String
+);
+
+impl<Seps, U> NdNum<Seps> for Vec<U>
+where
+    Seps: Separators,
+    U: NdNum<Seps>,
+{
+    const DEPTH: usize = U::DEPTH + 1;
+
+    fn to_nd_string(&self) -> String {
+        let sep = Seps::separators()[Self::DEPTH - 1];
+        let mut buf = [0u8; 4];
+        self.iter()
+            .map(|v| escaping::escape(&v.to_nd_string(), sep))
+            .collect::<Vec<_>>()
+            .join(sep.encode_utf8(&mut buf) as &str)
+    }
+
+    fn from_nd_str(s: &str) -> Result<Self, String> {
+        if s.is_empty() {
+            return Ok(Vec::new());
+        }
+        let sep = Seps::separators()[Self::DEPTH - 1];
+        escaping::split(s, sep)
+            .into_iter()
+            .map(|part| U::from_nd_str(&part))
+            .collect()
+    }
+}
+
+/// `&[[vec![-1, 1], vec![1, -1]]]` <--> `-1_1|1_-1` and beyond: arbitrarily nested
+/// `Vec<Vec<...<T>>>` with one delimiter per level, picked by a [`Separators`] type param
+///
+/// `#[serde(with = "...")]` can't pass generic params, so plug this in via
+/// `serialize_with`/`deserialize_with` and a turbofish instead:
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serde_csv_extra::{nd_num, DefaultSeparators};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo {
+///     #[serde(
+///         serialize_with = "nd_num::serialize::<_, DefaultSeparators, _>",
+///         deserialize_with = "nd_num::deserialize::<_, DefaultSeparators, _>"
+///     )]
+///     cube: Vec<Vec<Vec<i32>>>,
+/// }
+/// ```
+pub mod nd_num {
+    use super::{NdNum, Separators};
+    use serde::{
+        self,
+        de::Error as DeError,
+        ser::Error as SerError,
+        Deserialize, Deserializer, Serializer,
+    };
+
+    /// `T::DEPTH` indexes `Seps::separators()` up to `T::DEPTH - 1`; since `NdNum` is
+    /// blanket-implemented for `Vec<U>` at any depth, nothing at the type level stops `T`
+    /// from nesting deeper than the configured stack is long. Check that here instead of
+    /// letting [`NdNum::to_nd_string`]/[`NdNum::from_nd_str`] index out of bounds.
+    ///
+    /// Also reject a `'\0'` anywhere in the stack: `escaping` reserves it as the
+    /// empty-element marker, so a `'\0'` separator would make a literal NUL inside an
+    /// element indistinguishable from that marker.
+    fn check_depth<Seps: Separators, T: NdNum<Seps>>() -> Result<(), String> {
+        let available = Seps::separators().len();
+        if T::DEPTH > available {
+            return Err(format!(
+                "nd_num: nesting depth {} exceeds the {available} configured separators",
+                T::DEPTH
+            ));
+        }
+        if Seps::separators().contains(&'\0') {
+            return Err(
+                "nd_num: Separators::separators must not contain '\\0': escaping reserves \
+                 it as the empty-element marker"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Serializer
+    pub fn serialize<S, Seps, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Seps: Separators,
+        T: NdNum<Seps>,
+    {
+        check_depth::<Seps, T>().map_err(SerError::custom)?;
+        serializer.serialize_str(&value.to_nd_string())
+    }
+
+    /// Deserializer
+    pub fn deserialize<'de, D, Seps, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        Seps: Separators,
+        T: NdNum<Seps>,
+    {
+        check_depth::<Seps, T>().map_err(DeError::custom)?;
+        let s = String::deserialize(deserializer)?;
+        T::from_nd_str(&s).map_err(DeError::custom)
+    }
+}
+
 /// `Some((128, 64))` <--> `128x64`
 pub mod maybe_image_size {
     use serde::{self, de::Error, Deserialize, Deserializer, Serializer};
@@ -206,6 +825,367 @@ pub mod maybe_lat_lon {
     }
 }
 
+/// `Some((57.64911, 10.40744))` <--> `u4pruydqqvj` (at `PRECISION = 11`)
+///
+/// A more compact, prefix-sortable alternative to [`maybe_lat_lon`]: a single base32
+/// [geohash](https://en.wikipedia.org/wiki/Geohash) string instead of two floats.
+///
+/// `#[serde(with = "...")]` can't pass the precision, so plug this in via
+/// `serialize_with`/`deserialize_with` and a turbofish instead:
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serde_csv_extra::maybe_geohash;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo {
+///     #[serde(
+///         serialize_with = "maybe_geohash::serialize::<_, 9>",
+///         deserialize_with = "maybe_geohash::deserialize::<_, 9>"
+///     )]
+///     geo: Option<(f64, f64)>,
+/// }
+/// ```
+pub mod maybe_geohash {
+    use serde::{
+        self,
+        de::Error as DeError,
+        ser::Error as SerError,
+        Deserialize, Deserializer, Serializer,
+    };
+
+    const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+    /// Serializer, encoding to a `PRECISION`-character base32 geohash
+    pub fn serialize<S, const PRECISION: usize>(
+        coords: &Option<(f64, f64)>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if PRECISION == 0 {
+            return Err(SerError::custom(
+                "maybe_geohash: PRECISION must be at least 1, or the empty geohash would \
+                 collide with None's \"\" sentinel",
+            ));
+        }
+        match coords {
+            Some((lat, lon)) => serializer.serialize_str(&encode(*lat, *lon, PRECISION)),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    /// Deserializer, decoding to the center of the final geohash cell
+    pub fn deserialize<'de, D, const PRECISION: usize>(
+        deserializer: D,
+    ) -> Result<Option<(f64, f64)>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if PRECISION == 0 {
+            return Err(DeError::custom(
+                "maybe_geohash: PRECISION must be at least 1, or the empty geohash would \
+                 collide with None's \"\" sentinel",
+            ));
+        }
+        let s = String::deserialize(deserializer)?;
+        if s.is_empty() {
+            return Ok(None);
+        }
+        decode(&s).map(Some).map_err(DeError::custom)
+    }
+
+    /// Narrow `lat_range`/`lon_range` one bit at a time, alternating starting with
+    /// longitude, grouping every 5 bits into one base32 character.
+    fn encode(lat: f64, lon: f64, precision: usize) -> String {
+        let mut lat_range = (-90.0, 90.0);
+        let mut lon_range = (-180.0, 180.0);
+        let mut even_bit = true;
+        let mut bits = 0u8;
+        let mut bit_count = 0;
+        let mut out = String::with_capacity(precision);
+
+        while out.len() < precision {
+            let (value, range) = if even_bit {
+                (lon, &mut lon_range)
+            } else {
+                (lat, &mut lat_range)
+            };
+            let mid = (range.0 + range.1) / 2.0;
+            bits <<= 1;
+            if value >= mid {
+                bits |= 1;
+                range.0 = mid;
+            } else {
+                range.1 = mid;
+            }
+            even_bit = !even_bit;
+
+            bit_count += 1;
+            if bit_count == 5 {
+                out.push(ALPHABET[bits as usize] as char);
+                bits = 0;
+                bit_count = 0;
+            }
+        }
+        out
+    }
+
+    /// Reverses [`encode`], returning the center of the final cell.
+    fn decode(s: &str) -> Result<(f64, f64), String> {
+        let mut lat_range = (-90.0, 90.0);
+        let mut lon_range = (-180.0, 180.0);
+        let mut even_bit = true;
+
+        for c in s.chars() {
+            let bits = ALPHABET
+                .iter()
+                .position(|&a| a as char == c)
+                .ok_or_else(|| format!("invalid geohash character: {c:?}"))?;
+            for shift in (0..5).rev() {
+                let bit = (bits >> shift) & 1;
+                let range = if even_bit {
+                    &mut lon_range
+                } else {
+                    &mut lat_range
+                };
+                let mid = (range.0 + range.1) / 2.0;
+                if bit == 1 {
+                    range.0 = mid;
+                } else {
+                    range.1 = mid;
+                }
+                even_bit = !even_bit;
+            }
+        }
+
+        Ok((
+            (lat_range.0 + lat_range.1) / 2.0,
+            (lon_range.0 + lon_range.1) / 2.0,
+        ))
+    }
+}
+
+/// Type-level base64 alphabet, à la [`Separator`].
+///
+/// Implement this for a zero-sized marker type to plug a new alphabet into [`base64_with`].
+pub trait Base64Alphabet {
+    /// The 64-character alphabet, in index order.
+    fn alphabet() -> &'static [u8; 64];
+}
+
+/// RFC 4648 standard alphabet (`+`, `/`)
+pub struct StandardAlphabet;
+
+impl Base64Alphabet for StandardAlphabet {
+    fn alphabet() -> &'static [u8; 64] {
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+    }
+}
+
+/// RFC 4648 URL- and filename-safe alphabet (`-`, `_`)
+pub struct UrlSafeAlphabet;
+
+impl Base64Alphabet for UrlSafeAlphabet {
+    fn alphabet() -> &'static [u8; 64] {
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+    }
+}
+
+/// `&[1, 2, 3]` <--> `AQID`, with the alphabet picked by a [`Base64Alphabet`] type param
+///
+/// `#[serde(with = "...")]` can't pass generic params, so plug this in via
+/// `serialize_with`/`deserialize_with` and a turbofish instead:
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serde_csv_extra::{base64_with, UrlSafeAlphabet};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Foo {
+///     #[serde(
+///         serialize_with = "base64_with::serialize::<_, UrlSafeAlphabet>",
+///         deserialize_with = "base64_with::deserialize::<_, UrlSafeAlphabet>"
+///     )]
+///     thumbnail: Vec<u8>,
+/// }
+/// ```
+///
+/// [`bytes_base64`] is a thin wrapper over this with [`StandardAlphabet`].
+pub mod base64_with {
+    use super::Base64Alphabet;
+    use serde::{self, de::Error, Deserialize, Deserializer, Serializer};
+
+    /// Serializer, producing a single base64 string with no padding surprises
+    pub fn serialize<S, Alphabet>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Alphabet: Base64Alphabet,
+    {
+        serializer.serialize_str(&encode::<Alphabet>(bytes))
+    }
+
+    /// Deserializer, reversing [`serialize`]; an empty string decodes to an empty vector
+    pub fn deserialize<'de, D, Alphabet>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Alphabet: Base64Alphabet,
+    {
+        let s = String::deserialize(deserializer)?;
+        if s.is_empty() {
+            return Ok(Vec::new());
+        }
+        decode::<Alphabet>(&s).map_err(Error::custom)
+    }
+
+    /// Encode 3-byte groups into 4 base64 characters, padding the last group with `=`
+    fn encode<Alphabet: Base64Alphabet>(bytes: &[u8]) -> String {
+        let table = Alphabet::alphabet();
+        let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let b0 = chunk[0];
+            let b1 = chunk.get(1).copied().unwrap_or(0);
+            let b2 = chunk.get(2).copied().unwrap_or(0);
+            let n = (u32::from(b0) << 16) | (u32::from(b1) << 8) | u32::from(b2);
+            out.push(table[(n >> 18 & 0x3f) as usize] as char);
+            out.push(table[(n >> 12 & 0x3f) as usize] as char);
+            out.push(if chunk.len() > 1 {
+                table[(n >> 6 & 0x3f) as usize] as char
+            } else {
+                '='
+            });
+            out.push(if chunk.len() > 2 {
+                table[(n & 0x3f) as usize] as char
+            } else {
+                '='
+            });
+        }
+        out
+    }
+
+    /// Decode 4-character base64 groups back into bytes, rejecting bad length, characters
+    /// outside `Alphabet`, or misplaced padding
+    fn decode<Alphabet: Base64Alphabet>(s: &str) -> Result<Vec<u8>, String> {
+        let table = Alphabet::alphabet();
+        let bytes = s.as_bytes();
+        if !bytes.len().is_multiple_of(4) {
+            return Err(format!("base64 length {} is not a multiple of 4", bytes.len()));
+        }
+
+        let index_of = |c: u8| -> Result<u32, String> {
+            table
+                .iter()
+                .position(|&a| a == c)
+                .map(|i| i as u32)
+                .ok_or_else(|| format!("invalid base64 character: {:?}", c as char))
+        };
+
+        let mut out = Vec::with_capacity(bytes.len() / 4 * 3);
+        let last_chunk = bytes.len() / 4 - 1;
+        for (i, chunk) in bytes.chunks(4).enumerate() {
+            let padding = chunk.iter().rev().take_while(|&&b| b == b'=').count();
+            if padding > 2 || (i != last_chunk && padding > 0) {
+                return Err("invalid base64 padding".to_string());
+            }
+            let c0 = index_of(chunk[0])?;
+            let c1 = index_of(chunk[1])?;
+            let c2 = if padding < 2 { index_of(chunk[2])? } else { 0 };
+            let c3 = if padding < 1 { index_of(chunk[3])? } else { 0 };
+            let n = (c0 << 18) | (c1 << 12) | (c2 << 6) | c3;
+            out.push((n >> 16) as u8);
+            if padding < 2 {
+                out.push((n >> 8) as u8);
+            }
+            if padding < 1 {
+                out.push(n as u8);
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// `&[1, 2, 3]` <--> `AQID`
+///
+/// A thin wrapper over [`base64_with`] fixed to [`StandardAlphabet`]. Use [`base64_with`]
+/// directly for the URL-safe alphabet.
+pub mod bytes_base64 {
+    use super::{base64_with, StandardAlphabet};
+    use serde::{Deserializer, Serializer};
+
+    /// Serializer
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        base64_with::serialize::<S, StandardAlphabet>(bytes, serializer)
+    }
+
+    /// Deserializer
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        base64_with::deserialize::<D, StandardAlphabet>(deserializer)
+    }
+}
+
+/// `&[1, 2, 3]` <--> `010203`
+pub mod bytes_hex {
+    use serde::{self, de::Error, Deserialize, Deserializer, Serializer};
+
+    const ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+    /// Serializer, producing a lowercase hex string
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut out = String::with_capacity(bytes.len() * 2);
+        for b in bytes {
+            out.push(ALPHABET[(b >> 4) as usize] as char);
+            out.push(ALPHABET[(b & 0xf) as usize] as char);
+        }
+        serializer.serialize_str(&out)
+    }
+
+    /// Deserializer, accepting either case; an empty string decodes to an empty vector
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        if s.is_empty() {
+            return Ok(Vec::new());
+        }
+        let bytes = s.as_bytes();
+        if !bytes.len().is_multiple_of(2) {
+            return Err(Error::custom(format!(
+                "hex string length {} is not even",
+                bytes.len()
+            )));
+        }
+        bytes
+            .chunks(2)
+            .map(|pair| {
+                let hi = hex_digit(pair[0]).map_err(Error::custom)?;
+                let lo = hex_digit(pair[1]).map_err(Error::custom)?;
+                Ok(hi << 4 | lo)
+            })
+            .collect()
+    }
+
+    /// Parse a single hex digit, upper or lower case
+    fn hex_digit(c: u8) -> Result<u8, String> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(format!("invalid hex character: {:?}", c as char)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,6 +1215,124 @@ mod tests {
         assert_eq!(deserialized, empty);
     }
 
+    #[test]
+    fn vec_num_escaping() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(#[serde(with = "vec_num")] Vec<String>);
+
+        // an element containing the delimiter
+        let foo = Foo(vec!["a_b".to_string()]);
+        let foo_str = "\"a\\\\_b\"";
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serialized, foo_str);
+        let deserialized: Foo = serde_json::from_str(foo_str).unwrap();
+        assert_eq!(deserialized, foo);
+
+        // a trailing backslash, the delimiter, and an empty segment all at once
+        let foo = Foo(vec!["a_b".to_string(), String::new(), r"c\".to_string()]);
+        let serialized = serde_json::to_string(&foo).unwrap();
+        let deserialized: Foo = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, foo);
+    }
+
+    #[test]
+    fn vec_num_single_empty_element_vs_empty_vec() {
+        // a one-element list of "" must not collapse into a zero-element list
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(#[serde(with = "vec_num")] Vec<String>);
+
+        let one_empty = Foo(vec![String::new()]);
+        let serialized = serde_json::to_string(&one_empty).unwrap();
+        assert_ne!(serialized, "\"\"");
+        assert_eq!(serde_json::from_str::<Foo>(&serialized).unwrap(), one_empty);
+
+        let zero = Foo(vec![]);
+        assert_eq!(serde_json::to_string(&zero).unwrap(), "\"\"");
+        assert_ne!(serialized, serde_json::to_string(&zero).unwrap());
+    }
+
+    #[test]
+    fn vec_num_rejects_nul_separator() {
+        // a separator of '\0' would collide with the empty-element marker and silently
+        // swallow a NUL embedded in real data - must error instead.
+        struct NulSeparator;
+        impl Separator for NulSeparator {
+            fn separator() -> char {
+                '\0'
+            }
+        }
+
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "sep_vec::serialize::<_, NulSeparator, _>",
+                deserialize_with = "sep_vec::deserialize::<_, NulSeparator, _>"
+            )]
+            Vec<String>,
+        );
+
+        let foo = Foo(vec!["a\0b".to_string()]);
+        assert!(serde_json::to_string(&foo).is_err());
+        assert!(serde_json::from_str::<Foo>("\"a\\u0000b\"").is_err());
+    }
+
+    #[test]
+    fn vec_num_raw() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "sep_vec::serialize_raw::<_, UnderscoreSeparator, _>",
+                deserialize_with = "sep_vec::deserialize_raw::<_, UnderscoreSeparator, _>"
+            )]
+            Vec<i32>,
+        );
+
+        let foo = Foo(vec![-1, 0, 3]);
+        let foo_str = "\"-1_0_3\"";
+
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serialized, foo_str);
+
+        let deserialized: Foo = serde_json::from_str(foo_str).unwrap();
+        assert_eq!(deserialized, foo);
+    }
+
+    #[test]
+    fn vec_num_pick_first() {
+        // `vec_num::deserialize` itself stays string-only for backward compatibility;
+        // `deserialize_pick_first` is the opt-in that also accepts a native sequence.
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "vec_num::serialize",
+                deserialize_with = "vec_num::deserialize_pick_first"
+            )]
+            Vec<i32>,
+        );
+
+        let from_string: Foo = serde_json::from_str("\"-1_1\"").unwrap();
+        let from_seq: Foo = serde_json::from_str("[-1,1]").unwrap();
+        assert_eq!(from_string, Foo(vec![-1, 1]));
+        assert_eq!(from_seq, Foo(vec![-1, 1]));
+    }
+
+    #[test]
+    fn vec_vec_num_pick_first() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "vec_vec_num::serialize",
+                deserialize_with = "vec_vec_num::deserialize_pick_first"
+            )]
+            Vec<Vec<i32>>,
+        );
+
+        let from_string: Foo = serde_json::from_str("\"0|-1_1\"").unwrap();
+        let from_seq: Foo = serde_json::from_str("[[0],[-1,1]]").unwrap();
+        assert_eq!(from_string, Foo(vec![vec![0], vec![-1, 1]]));
+        assert_eq!(from_seq, Foo(vec![vec![0], vec![-1, 1]]));
+    }
+
     #[test]
     fn vec_vec_i32() {
         #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -259,6 +1357,168 @@ mod tests {
         assert_eq!(deserialized, empty);
     }
 
+    #[test]
+    fn vec_vec_escaping() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(#[serde(with = "vec_vec_num")] Vec<Vec<String>>);
+
+        // elements containing either separator, a trailing backslash, and an empty segment
+        let foo = Foo(vec![
+            vec!["a_b".to_string(), "c|d".to_string()],
+            vec![String::new(), r"e\".to_string()],
+        ]);
+        let serialized = serde_json::to_string(&foo).unwrap();
+        let deserialized: Foo = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, foo);
+    }
+
+    #[test]
+    fn vec_vec_num_row_of_single_empty_element_vs_empty_row() {
+        // a row that's a single "" must not collapse into an empty row
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(#[serde(with = "vec_vec_num")] Vec<Vec<String>>);
+
+        let foo = Foo(vec![vec![String::new()], vec![]]);
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serde_json::from_str::<Foo>(&serialized).unwrap(), foo);
+    }
+
+    #[test]
+    fn nd_num_depths() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo1(
+            #[serde(
+                serialize_with = "nd_num::serialize::<_, DefaultSeparators, _>",
+                deserialize_with = "nd_num::deserialize::<_, DefaultSeparators, _>"
+            )]
+            i32,
+        );
+
+        let foo = Foo1(-1);
+        let foo_str = "\"-1\"";
+        assert_eq!(serde_json::to_string(&foo).unwrap(), foo_str);
+        assert_eq!(serde_json::from_str::<Foo1>(foo_str).unwrap(), foo);
+
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo2(
+            #[serde(
+                serialize_with = "nd_num::serialize::<_, DefaultSeparators, _>",
+                deserialize_with = "nd_num::deserialize::<_, DefaultSeparators, _>"
+            )]
+            Vec<i32>,
+        );
+
+        let foo = Foo2(vec![-1, 0, 3]);
+        let foo_str = "\"-1_0_3\"";
+        assert_eq!(serde_json::to_string(&foo).unwrap(), foo_str);
+        assert_eq!(serde_json::from_str::<Foo2>(foo_str).unwrap(), foo);
+
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo3(
+            #[serde(
+                serialize_with = "nd_num::serialize::<_, DefaultSeparators, _>",
+                deserialize_with = "nd_num::deserialize::<_, DefaultSeparators, _>"
+            )]
+            Vec<Vec<i32>>,
+        );
+
+        // an empty inner vec, alongside a non-empty one - round-trips, but no longer has a
+        // simple literal form since the empty inner vec now needs its own marker to stay
+        // distinct from a single empty-string leaf
+        let foo = Foo3(vec![vec![], vec![-1, 1]]);
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serde_json::from_str::<Foo3>(&serialized).unwrap(), foo);
+
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo4(
+            #[serde(
+                serialize_with = "nd_num::serialize::<_, DefaultSeparators, _>",
+                deserialize_with = "nd_num::deserialize::<_, DefaultSeparators, _>"
+            )]
+            Vec<Vec<Vec<i32>>>,
+        );
+
+        let foo = Foo4(vec![vec![vec![1, 2], vec![]], vec![vec![3]]]);
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serde_json::from_str::<Foo4>(&serialized).unwrap(), foo);
+
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo5(
+            #[serde(
+                serialize_with = "nd_num::serialize::<_, DefaultSeparators, _>",
+                deserialize_with = "nd_num::deserialize::<_, DefaultSeparators, _>"
+            )]
+            Vec<Vec<Vec<Vec<i32>>>>,
+        );
+
+        let foo = Foo5(vec![vec![
+            vec![vec![1], vec![2, 3]],
+            vec![vec![4], vec![]],
+        ]]);
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serde_json::from_str::<Foo5>(&serialized).unwrap(), foo);
+    }
+
+    #[test]
+    fn nd_num_depth_overflow() {
+        // 5 levels of nesting against `DefaultSeparators`'s 4 entries: errors instead of
+        // panicking with an out-of-bounds index.
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "nd_num::serialize::<_, DefaultSeparators, _>",
+                deserialize_with = "nd_num::deserialize::<_, DefaultSeparators, _>"
+            )]
+            Vec<Vec<Vec<Vec<Vec<i32>>>>>,
+        );
+
+        let foo = Foo(vec![vec![vec![vec![vec![1]]]]]);
+        assert!(serde_json::to_string(&foo).is_err());
+        assert!(serde_json::from_str::<Foo>("\"1\"").is_err());
+    }
+
+    #[test]
+    fn nd_num_single_empty_element_vs_empty_vec() {
+        // a row that's a single "" must not collapse into an empty row, at any depth
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "nd_num::serialize::<_, DefaultSeparators, _>",
+                deserialize_with = "nd_num::deserialize::<_, DefaultSeparators, _>"
+            )]
+            Vec<Vec<String>>,
+        );
+
+        let foo = Foo(vec![vec![String::new()], vec![]]);
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serde_json::from_str::<Foo>(&serialized).unwrap(), foo);
+    }
+
+    #[test]
+    fn nd_num_rejects_nul_separator() {
+        // same collision as `vec_num_rejects_nul_separator`, but for a stack entry rather
+        // than a single `Separator`.
+        struct NulSeparators;
+        impl Separators for NulSeparators {
+            fn separators() -> &'static [char] {
+                &['_', '\0']
+            }
+        }
+
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "nd_num::serialize::<_, NulSeparators, _>",
+                deserialize_with = "nd_num::deserialize::<_, NulSeparators, _>"
+            )]
+            Vec<Vec<String>>,
+        );
+
+        let foo = Foo(vec![vec!["a\0b".to_string()]]);
+        assert!(serde_json::to_string(&foo).is_err());
+        assert!(serde_json::from_str::<Foo>("\"a\\u0000b\"").is_err());
+    }
+
     #[test]
     fn img_size() {
         #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -306,4 +1566,142 @@ mod tests {
         let deserialized: Foo = serde_json::from_str(empty_str).unwrap();
         assert_eq!(deserialized, empty);
     }
+
+    #[test]
+    fn geohash_roundtrip() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "maybe_geohash::serialize::<_, 9>",
+                deserialize_with = "maybe_geohash::deserialize::<_, 9>"
+            )]
+            Option<(f64, f64)>,
+        );
+
+        let foo = Foo(Some((57.64911, 10.40744)));
+        let serialized = serde_json::to_string(&foo).unwrap();
+        let Foo(decoded) = serde_json::from_str(&serialized).unwrap();
+        let (lat, lon) = decoded.unwrap();
+        assert!((lat - 57.64911).abs() < 0.001, "lat {lat} not close enough");
+        assert!((lon - 10.40744).abs() < 0.001, "lon {lon} not close enough");
+
+        let empty = Foo(None);
+        let empty_str = "\"\"";
+
+        let serialized = serde_json::to_string(&empty).unwrap();
+        assert_eq!(serialized, empty_str);
+
+        let deserialized: Foo = serde_json::from_str(empty_str).unwrap();
+        assert_eq!(deserialized, empty);
+    }
+
+    #[test]
+    fn geohash_invalid_char() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "maybe_geohash::serialize::<_, 9>",
+                deserialize_with = "maybe_geohash::deserialize::<_, 9>"
+            )]
+            Option<(f64, f64)>,
+        );
+
+        assert!(serde_json::from_str::<Foo>("\"a!\"").is_err());
+    }
+
+    #[test]
+    fn geohash_zero_precision_errors() {
+        // PRECISION = 0 would make every coordinate encode to "", the same sentinel used
+        // for None, so it must error rather than silently collapsing Some into None.
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "maybe_geohash::serialize::<_, 0>",
+                deserialize_with = "maybe_geohash::deserialize::<_, 0>"
+            )]
+            Option<(f64, f64)>,
+        );
+
+        assert!(serde_json::to_string(&Foo(Some((10.0, 20.0)))).is_err());
+        assert!(serde_json::from_str::<Foo>("\"\"").is_err());
+    }
+
+    #[test]
+    fn base64_roundtrip() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(#[serde(with = "bytes_base64")] Vec<u8>);
+
+        let foo = Foo(vec![0, 1, 2, 3, 250, 251, 252, 253, 254, 255]);
+        let serialized = serde_json::to_string(&foo).unwrap();
+        assert_eq!(serde_json::from_str::<Foo>(&serialized).unwrap(), foo);
+
+        let empty = Foo(vec![]);
+        let empty_str = "\"\"";
+        assert_eq!(serde_json::to_string(&empty).unwrap(), empty_str);
+        assert_eq!(serde_json::from_str::<Foo>(empty_str).unwrap(), empty);
+    }
+
+    #[test]
+    fn base64_url_safe() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(
+            #[serde(
+                serialize_with = "base64_with::serialize::<_, UrlSafeAlphabet>",
+                deserialize_with = "base64_with::deserialize::<_, UrlSafeAlphabet>"
+            )]
+            Vec<u8>,
+        );
+
+        // a byte sequence whose standard encoding would contain `+` and `/`
+        let foo = Foo(vec![0xfb, 0xff, 0xbf]);
+        let foo_str = "\"-_-_\"";
+        assert_eq!(serde_json::to_string(&foo).unwrap(), foo_str);
+        assert_eq!(serde_json::from_str::<Foo>(foo_str).unwrap(), foo);
+    }
+
+    #[test]
+    fn base64_invalid() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(#[serde(with = "bytes_base64")] Vec<u8>);
+
+        // wrong length
+        assert!(serde_json::from_str::<Foo>("\"AQ\"").is_err());
+        // character outside the alphabet
+        assert!(serde_json::from_str::<Foo>("\"AQI!\"").is_err());
+        // padding in the middle of a group
+        assert!(serde_json::from_str::<Foo>("\"A=AA\"").is_err());
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(#[serde(with = "bytes_hex")] Vec<u8>);
+
+        let foo = Foo(vec![0, 1, 2, 250, 251, 252, 253, 254, 255]);
+        let foo_str = "\"000102fafbfcfdfeff\"";
+        assert_eq!(serde_json::to_string(&foo).unwrap(), foo_str);
+        assert_eq!(serde_json::from_str::<Foo>(foo_str).unwrap(), foo);
+
+        // accepts uppercase too
+        assert_eq!(
+            serde_json::from_str::<Foo>("\"00FF\"").unwrap(),
+            Foo(vec![0, 255])
+        );
+
+        let empty = Foo(vec![]);
+        let empty_str = "\"\"";
+        assert_eq!(serde_json::to_string(&empty).unwrap(), empty_str);
+        assert_eq!(serde_json::from_str::<Foo>(empty_str).unwrap(), empty);
+    }
+
+    #[test]
+    fn hex_invalid() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Foo(#[serde(with = "bytes_hex")] Vec<u8>);
+
+        // odd length
+        assert!(serde_json::from_str::<Foo>("\"abc\"").is_err());
+        // character outside the hex alphabet
+        assert!(serde_json::from_str::<Foo>("\"zz\"").is_err());
+    }
 }